@@ -0,0 +1,177 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+/// The champ-select lobby's overall phase, from `timer.phase`. Unknown
+/// values (Riot adds phases over time) fall through to `Unknown` instead of
+/// failing deserialization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChampSelectPhase {
+    Planning,
+    BanPick,
+    Finalization,
+    Unknown(String),
+}
+
+impl FromStr for ChampSelectPhase {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "PLANNING" => ChampSelectPhase::Planning,
+            "BAN_PICK" => ChampSelectPhase::BanPick,
+            "FINALIZATION" => ChampSelectPhase::Finalization,
+            other => ChampSelectPhase::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Default for ChampSelectPhase {
+    fn default() -> Self {
+        ChampSelectPhase::Unknown(String::new())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChampSelectPhase {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap())
+    }
+}
+
+/// A champ-select action's kind, from `actions[][].type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ActionType {
+    Ban,
+    Pick,
+    Unknown(String),
+}
+
+impl FromStr for ActionType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ban" => ActionType::Ban,
+            "pick" => ActionType::Pick,
+            other => ActionType::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap())
+    }
+}
+
+/// The client's overall gameflow phase, from `/lol-gameflow/v1/gameflow-phase`.
+/// Mirrors the phases Riven's `GameflowPhase` const tracks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GameflowPhase {
+    None,
+    Lobby,
+    Matchmaking,
+    CheckedIntoTournament,
+    ReadyCheck,
+    ChampSelect,
+    GameStart,
+    FailedToLaunch,
+    InProgress,
+    InGame,
+    Reconnect,
+    WaitingForStats,
+    PreEndOfGame,
+    EndOfGame,
+    TerminatedInError,
+    WatchInProgress,
+    Unknown(String),
+}
+
+impl FromStr for GameflowPhase {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "None" => GameflowPhase::None,
+            "Lobby" => GameflowPhase::Lobby,
+            "Matchmaking" => GameflowPhase::Matchmaking,
+            "CheckedIntoTournament" => GameflowPhase::CheckedIntoTournament,
+            "ReadyCheck" => GameflowPhase::ReadyCheck,
+            "ChampSelect" => GameflowPhase::ChampSelect,
+            "GameStart" => GameflowPhase::GameStart,
+            "FailedToLaunch" => GameflowPhase::FailedToLaunch,
+            "InProgress" => GameflowPhase::InProgress,
+            "InGame" => GameflowPhase::InGame,
+            "Reconnect" => GameflowPhase::Reconnect,
+            "WaitingForStats" => GameflowPhase::WaitingForStats,
+            "PreEndOfGame" => GameflowPhase::PreEndOfGame,
+            "EndOfGame" => GameflowPhase::EndOfGame,
+            "TerminatedInError" => GameflowPhase::TerminatedInError,
+            "WatchInProgress" => GameflowPhase::WatchInProgress,
+            other => GameflowPhase::Unknown(other.to_string()),
+        })
+    }
+}
+
+/// A champ-select teammate entry from `myTeam`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TeamMember {
+    #[serde(rename = "cellId")]
+    pub cell_id: i64,
+    /// Raw LCU value, e.g. "top"/"jungle"/""; the LCU reports this lowercase,
+    /// so callers should compare it case-insensitively.
+    #[serde(rename = "assignedPosition", default)]
+    pub assigned_position: String,
+}
+
+/// A single ban/pick action from `actions[][]`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Action {
+    pub id: i64,
+    #[serde(rename = "actorCellId")]
+    pub actor_cell_id: i64,
+    #[serde(rename = "type")]
+    pub action_type: ActionType,
+    #[serde(rename = "championId", default)]
+    pub champion_id: u32,
+    #[serde(default)]
+    pub completed: bool,
+    #[serde(rename = "isInProgress", default)]
+    pub is_in_progress: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Timer {
+    #[serde(default)]
+    pub phase: ChampSelectPhase,
+}
+
+/// The `data` payload of an `OnJsonApiEvent` for
+/// `/lol-champ-select/v1/session`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChampSelectSession {
+    #[serde(rename = "localPlayerCellId", default = "default_cell_id")]
+    pub local_player_cell_id: i64,
+    #[serde(default)]
+    pub timer: Timer,
+    #[serde(rename = "myTeam", default)]
+    pub my_team: Vec<TeamMember>,
+    #[serde(default)]
+    pub actions: Vec<Vec<Action>>,
+}
+
+fn default_cell_id() -> i64 {
+    -1
+}
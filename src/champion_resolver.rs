@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+/// Display-name / internal-key mismatches that a human would type but that
+/// don't match DataDragon's internal champion id (e.g. "Wukong" is typed in
+/// champ select but DataDragon's id for him is "MonkeyKing").
+const ALIASES: &[(&str, &str)] = &[
+    ("wukong", "MonkeyKing"),
+    ("monkey king", "MonkeyKing"),
+    ("renata glasc", "Renata"),
+    ("mundo", "DrMundo"),
+    ("dr. mundo", "DrMundo"),
+    ("kai'sa", "Kaisa"),
+    ("cho'gath", "Chogath"),
+    ("kha'zix", "Khazix"),
+    ("vel'koz", "Velkoz"),
+    ("kog'maw", "KogMaw"),
+    ("jarvan", "JarvanIV"),
+    ("jarvan iv", "JarvanIV"),
+    ("nunu and willump", "Nunu"),
+    ("nunu & willump", "Nunu"),
+];
+
+/// Maximum normalized Levenshtein distance (edits / longer-string length)
+/// allowed for a fuzzy match, e.g. `kasia` -> `kaisa` still resolves.
+const FUZZY_THRESHOLD: f64 = 0.4;
+
+/// Resolves a user-typed champion name — DataDragon internal key, display
+/// name, common alias, or a near-miss typo — to DataDragon's canonical
+/// internal key (the string `champions.get()` expects).
+pub struct ChampionResolver {
+    /// Lowercased search term (key, display name, or alias) -> canonical key.
+    index: HashMap<String, String>,
+}
+
+impl ChampionResolver {
+    pub fn new(canonical_keys: impl IntoIterator<Item = String>, display_names: &HashMap<String, String>) -> Self {
+        let mut index = HashMap::new();
+
+        for key in canonical_keys {
+            index.insert(key.to_lowercase(), key);
+        }
+        for (key, name) in display_names {
+            index.entry(name.to_lowercase()).or_insert_with(|| key.clone());
+        }
+        for &(alias, key) in ALIASES {
+            if index.contains_key(&key.to_lowercase()) {
+                index.insert(alias.to_string(), key.to_string());
+            }
+        }
+
+        Self { index }
+    }
+
+    /// Resolves `query` to a canonical champion key, trying an exact
+    /// case-insensitive match first and falling back to the closest entry
+    /// within `FUZZY_THRESHOLD`.
+    pub fn resolve(&self, query: &str) -> Option<&str> {
+        let normalized = query.to_lowercase();
+
+        if let Some(key) = self.index.get(&normalized) {
+            return Some(key.as_str());
+        }
+
+        self.index
+            .iter()
+            .map(|(candidate, key)| (key.as_str(), normalized_levenshtein(&normalized, candidate)))
+            .filter(|(_, distance)| *distance <= FUZZY_THRESHOLD)
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(key, _)| key)
+    }
+}
+
+/// Levenshtein edit distance divided by the longer string's length, so the
+/// threshold scales with name length instead of being a fixed edit count.
+fn normalized_levenshtein(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 0.0;
+    }
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let deletion = row[j] + 1;
+            let insertion = row[j - 1] + 1;
+            let substitution = prev_diag + cost;
+            prev_diag = row[j];
+            row[j] = deletion.min(insertion).min(substitution);
+        }
+    }
+
+    row[b.len()] as f64 / max_len as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver() -> ChampionResolver {
+        let keys = ["MonkeyKing", "Ahri", "DrMundo", "Kaisa"].map(String::from);
+        let display_names = HashMap::from([
+            ("MonkeyKing".to_string(), "Wukong".to_string()),
+            ("Ahri".to_string(), "Ahri".to_string()),
+            ("DrMundo".to_string(), "Dr. Mundo".to_string()),
+            ("Kaisa".to_string(), "Kai'Sa".to_string()),
+        ]);
+        ChampionResolver::new(keys, &display_names)
+    }
+
+    #[test]
+    fn resolves_exact_canonical_key_case_insensitively() {
+        assert_eq!(resolver().resolve("ahri"), Some("Ahri"));
+    }
+
+    #[test]
+    fn resolves_display_name_that_differs_from_the_key() {
+        assert_eq!(resolver().resolve("Dr. Mundo"), Some("DrMundo"));
+    }
+
+    #[test]
+    fn resolves_known_alias() {
+        assert_eq!(resolver().resolve("wukong"), Some("MonkeyKing"));
+    }
+
+    #[test]
+    fn resolves_close_typo_via_fuzzy_match() {
+        assert_eq!(resolver().resolve("kasia"), Some("Kaisa"));
+    }
+
+    #[test]
+    fn unresolvable_name_returns_none() {
+        assert_eq!(resolver().resolve("totallynotachampion"), None);
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde_json::Value;
+
+use anyhow::Result;
+
+/// A response from the LCU, shaped like `reqwest::Response` minus the parts
+/// that require a live connection, so a mock can hand back the same type a
+/// real request would.
+pub struct LcuResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl LcuResponse {
+    fn ok() -> Self {
+        Self {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: String::new(),
+        }
+    }
+
+    /// Builds a bare response with the given status and no headers/body, for
+    /// scripting `MockLcuClient` in tests (e.g. a 429 or 5xx to exercise
+    /// `submit_action`'s retry path, or a 4xx for "champion unavailable").
+    #[cfg(test)]
+    pub fn with_status(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: String::new(),
+        }
+    }
+
+    /// Like `with_status`, but with a `Retry-After: <seconds>` header, for
+    /// scripting the LCU's backoff hint on a 429/5xx.
+    #[cfg(test)]
+    pub fn with_retry_after(status: StatusCode, retry_after_secs: u64) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            retry_after_secs.to_string().parse().unwrap(),
+        );
+        Self { status, headers, body: String::new() }
+    }
+}
+
+/// Everything `handle_message`'s pick/ban logic needs from an HTTP client,
+/// so it can run against a live `reqwest::Client` or a `MockLcuClient` in
+/// tests. Paths are relative to the LCU's `https://127.0.0.1:<port>` root.
+#[async_trait]
+pub trait LcuClient: Send + Sync {
+    async fn get(&self, path: &str) -> Result<LcuResponse>;
+    async fn post(&self, path: &str) -> Result<LcuResponse>;
+    async fn patch(&self, path: &str, body: Value) -> Result<LcuResponse>;
+}
+
+/// The real transport: a `reqwest::Client` already configured with the LCU's
+/// basic-auth header, plus the base URL for the discovered port.
+pub struct ReqwestLcuClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl ReqwestLcuClient {
+    pub fn new(client: reqwest::Client, port: u16) -> Self {
+        Self {
+            client,
+            base_url: format!("https://127.0.0.1:{}", port),
+        }
+    }
+
+    async fn to_lcu_response(response: reqwest::Response) -> Result<LcuResponse> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+        Ok(LcuResponse { status, headers, body })
+    }
+}
+
+#[async_trait]
+impl LcuClient for ReqwestLcuClient {
+    async fn get(&self, path: &str) -> Result<LcuResponse> {
+        let response = self.client.get(format!("{}{}", self.base_url, path)).send().await?;
+        Self::to_lcu_response(response).await
+    }
+
+    async fn post(&self, path: &str) -> Result<LcuResponse> {
+        let response = self.client.post(format!("{}{}", self.base_url, path)).send().await?;
+        Self::to_lcu_response(response).await
+    }
+
+    async fn patch(&self, path: &str, body: Value) -> Result<LcuResponse> {
+        let response = self.client.patch(format!("{}{}", self.base_url, path))
+            .json(&body)
+            .send()
+            .await?;
+        Self::to_lcu_response(response).await
+    }
+}
+
+#[cfg(test)]
+pub use mock::{MockLcuClient, RecordedCall};
+
+#[cfg(test)]
+mod mock {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone)]
+    pub struct RecordedCall {
+        pub method: &'static str,
+        pub path: String,
+        pub body: Option<Value>,
+    }
+
+    /// Records every call it receives instead of making one, so tests can
+    /// assert on the exact sequence of ban/pick PATCH payloads a session
+    /// produced. Responses are scripted via `push_response`, in FIFO order
+    /// across get/post/patch; once the queue is empty, calls fall back to
+    /// `LcuResponse::ok()`. This is what lets a test drive `submit_action`
+    /// through a 429-then-success, a genuine 4xx, or a full give-up.
+    #[derive(Default)]
+    pub struct MockLcuClient {
+        calls: Mutex<Vec<RecordedCall>>,
+        scripted_responses: Mutex<VecDeque<LcuResponse>>,
+    }
+
+    impl MockLcuClient {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn calls(&self) -> Vec<RecordedCall> {
+            self.calls.lock().unwrap().clone()
+        }
+
+        /// Queues `response` to be returned by the next call this client
+        /// receives.
+        pub fn push_response(&self, response: LcuResponse) {
+            self.scripted_responses.lock().unwrap().push_back(response);
+        }
+
+        fn next_response(&self) -> LcuResponse {
+            self.scripted_responses.lock().unwrap().pop_front().unwrap_or_else(LcuResponse::ok)
+        }
+    }
+
+    #[async_trait]
+    impl LcuClient for MockLcuClient {
+        async fn get(&self, path: &str) -> Result<LcuResponse> {
+            self.calls.lock().unwrap().push(RecordedCall { method: "GET", path: path.to_string(), body: None });
+            Ok(self.next_response())
+        }
+
+        async fn post(&self, path: &str) -> Result<LcuResponse> {
+            self.calls.lock().unwrap().push(RecordedCall { method: "POST", path: path.to_string(), body: None });
+            Ok(self.next_response())
+        }
+
+        async fn patch(&self, path: &str, body: Value) -> Result<LcuResponse> {
+            self.calls.lock().unwrap().push(RecordedCall { method: "PATCH", path: path.to_string(), body: Some(body) });
+            Ok(self.next_response())
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::process::exit;
 use std::time::Duration;
 use tokio::time::sleep;
-use serde_json::{Value, json};
+use serde_json::{Map, Value, json};
 use reqwest::{Client, ClientBuilder};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
@@ -13,35 +13,73 @@ use anyhow::{Result, anyhow};
 use url::Url;
 use std::sync::{Arc, Mutex};
 
+mod rate_limiter;
+use rate_limiter::RateLimiter;
+
+mod champion_resolver;
+use champion_resolver::ChampionResolver;
+
+mod champ_select;
+use champ_select::{Action, ActionType, ChampSelectSession, ChampSelectPhase, GameflowPhase};
+
+mod lcu_client;
+use lcu_client::{LcuClient, ReqwestLcuClient};
+
 #[derive(Debug)]
 struct GameState {
     am_i_assigned: bool,
-    am_i_picking: bool,
-    am_i_banning: bool,
-    pick_number: usize,
-    ban_number: usize,
-    phase: String,
+    phase: Option<ActionType>,
     in_game: bool,
-    have_i_prepicked: bool,
     action_id: Option<i64>,
+    /// `assignedPosition` for the local player as reported by the LCU, e.g.
+    /// "TOP" or lowercase "top"; empty when unassigned (blind pick, ARAM), in
+    /// which case `"default"` is used. Matched against `[picks.*]`/`[bans.*]`
+    /// case-insensitively, since the LCU itself reports this lowercase.
+    assigned_role: String,
+    /// Whether we've already checked (and warned, if needed) that
+    /// `assigned_role` has a matching `[picks.*]`/`[bans.*]` table. Only ever
+    /// checked once per connection so it doesn't spam on every session update.
+    warned_role_unconfigured: bool,
 }
 
 impl Default for GameState {
     fn default() -> Self {
         Self {
             am_i_assigned: false,
-            am_i_picking: false,
-            am_i_banning: false,
-            pick_number: 0,
-            ban_number: 0,
-            phase: String::new(),
+            phase: None,
             in_game: false,
-            have_i_prepicked: false,
             action_id: None,
+            assigned_role: String::new(),
+            warned_role_unconfigured: false,
         }
     }
 }
 
+/// Fallback key used when a player has no assigned position, or when the
+/// config has no list for their assigned position.
+const DEFAULT_ROLE: &str = "default";
+
+/// Looks up the pick/ban list for `role` in a `[picks]`/`[bans]` table keyed
+/// by `assignedPosition` (e.g. `TOP`, `JUNGLE`), falling back to `default`.
+/// The match is case-insensitive, since the LCU reports `assignedPosition`
+/// lowercase (`"top"`) while config files conventionally spell roles
+/// uppercase (`[picks.TOP]`).
+fn role_list<'a>(lists: &'a Map<String, Value>, role: &str) -> &'a [Value] {
+    lists.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(role))
+        .map(|(_, v)| v)
+        .or_else(|| lists.get(DEFAULT_ROLE))
+        .and_then(|v| v.as_array())
+        .map(|v| v.as_slice())
+        .unwrap_or(&[])
+}
+
+/// True if `lists` has a role-specific table for `role`, matched the same
+/// case-insensitive way as `role_list` (distinct from the `default` fallback).
+fn role_configured(lists: &Map<String, Value>, role: &str) -> bool {
+    lists.keys().any(|key| key.eq_ignore_ascii_case(role))
+}
+
 #[derive(Debug)]
 struct LcuConnection {
     port: u16,
@@ -88,34 +126,118 @@ impl LcuConnection {
     }
 }
 
+/// Starting interval between `LcuConnection::find()` polls while the client
+/// is unreachable; doubles on each failed attempt up to `RECONNECT_POLL_MAX`.
+const RECONNECT_POLL_MIN: Duration = Duration::from_secs(2);
+const RECONNECT_POLL_MAX: Duration = Duration::from_secs(30);
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load config
     let config = std::fs::read_to_string("config.toml")?;
     let config: Value = toml::from_str(&config)?;
-    
+
+    // Picks/bans are keyed by assigned position (e.g. `TOP`, `JUNGLE`), with
+    // a `default` list used when the role is unassigned or has no list of
+    // its own.
     let picks = config.get("picks")
-        .and_then(|v| v.as_array())
+        .and_then(|v| v.as_object())
         .ok_or_else(|| anyhow!("Missing or invalid picks in config"))?;
-    
+
     let bans = config.get("bans")
-        .and_then(|v| v.as_array()) 
+        .and_then(|v| v.as_object())
         .ok_or_else(|| anyhow!("Missing or invalid bans in config"))?;
 
-    if picks.is_empty() || bans.is_empty() {
-        eprintln!("Picks or bans list is empty in config.toml");
+    if role_list(picks, DEFAULT_ROLE).is_empty() || role_list(bans, DEFAULT_ROLE).is_empty() {
+        eprintln!("picks.default or bans.default is missing or empty in config.toml");
         exit(1);
     }
 
-    // Find League client
-    let connection = LcuConnection::find()
-        .ok_or_else(|| anyhow!("League client not found"))?;
+    let mut connection = find_connection_with_backoff().await;
+    let mut client = build_authed_client(&connection)?;
+
+    // Backs off on the websocket connect and subscribe failures below, on top
+    // of the separate backoff `find_connection_with_backoff` applies to
+    // process-discovery misses, so a client that's visible in the process
+    // list but whose WS/API server isn't up yet doesn't get hammered at zero
+    // delay. Reset to `RECONNECT_POLL_MIN` on every successful connect.
+    let mut reconnect_delay = RECONNECT_POLL_MIN;
+
+    // Optionally pin the DataDragon patch for reproducible behavior instead
+    // of always tracking whatever is currently live.
+    let pinned_version = config.get("champion_data_version").and_then(|v| v.as_str());
+
+    // Champion data survives reconnects; it's only ever re-fetched here.
+    let (champions, champion_resolver) = get_champions(&client, pinned_version).await?;
+
+    // Resolve configured champion names (aliases, display names, typos) to
+    // DataDragon's canonical keys once, so the hot lookup path below stays a
+    // plain HashMap::get.
+    let picks = resolve_champion_names(picks, &champion_resolver, "picks");
+    let bans = resolve_champion_names(bans, &champion_resolver, "bans");
 
-    // Setup HTTP client
+    let game_state = Arc::new(Mutex::new(GameState::default()));
+    let limiter = Arc::new(RateLimiter::default_lcu_limits());
+
+    // Supervising loop: whenever the socket drops or errors, rediscover the
+    // client (its lockfile port/token rotate on every relaunch), rebuild the
+    // authed client, and reconnect, instead of exiting.
+    loop {
+        let lcu_client = ReqwestLcuClient::new(client.clone(), connection.port);
+
+        let ws_url = format!("wss://127.0.0.1:{}", connection.port);
+        let mut ws_stream = match connect_async(Url::parse(&ws_url)?.to_string()).await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                eprintln!("Failed to connect to LCU websocket: {}", e);
+                sleep(reconnect_delay).await;
+                reconnect_delay = (reconnect_delay * 2).min(RECONNECT_POLL_MAX);
+                connection = find_connection_with_backoff().await;
+                client = build_authed_client(&connection)?;
+                continue;
+            }
+        };
+
+        if let Err(e) = ws_stream.send(Message::Text(json!([5, "OnJsonApiEvent"]).to_string())).await {
+            eprintln!("Failed to subscribe to LCU events: {}", e);
+            sleep(reconnect_delay).await;
+            reconnect_delay = (reconnect_delay * 2).min(RECONNECT_POLL_MAX);
+            connection = find_connection_with_backoff().await;
+            client = build_authed_client(&connection)?;
+            continue;
+        }
+
+        println!("Connected to League client on port {}", connection.port);
+        reconnect_delay = RECONNECT_POLL_MIN;
+        *game_state.lock().unwrap() = GameState::default();
+
+        while let Some(msg) = ws_stream.next().await {
+            let msg = match msg {
+                Ok(msg) => msg,
+                Err(e) => {
+                    eprintln!("Websocket error: {}", e);
+                    break;
+                }
+            };
+
+            if let Message::Text(text) = msg {
+                if let Err(e) = handle_message(&lcu_client, &text, &champions, &picks, &bans, Arc::clone(&game_state), Arc::clone(&limiter)).await {
+                    eprintln!("Error handling message: {}", e);
+                }
+            }
+        }
+
+        println!("Disconnected from League client, rediscovering...");
+        connection = find_connection_with_backoff().await;
+        client = build_authed_client(&connection)?;
+    }
+}
+
+fn build_authed_client(connection: &LcuConnection) -> Result<Client> {
     let auth = format!("riot:{}", connection.auth_token);
     let auth = format!("Basic {}", BASE64.encode(auth));
 
-    let client = ClientBuilder::new()
+    Ok(ClientBuilder::new()
         .danger_accept_invalid_certs(true)
         .default_headers({
             let mut headers = reqwest::header::HeaderMap::new();
@@ -123,42 +245,117 @@ async fn main() -> Result<()> {
             headers.insert("Content-Type", "application/json".parse()?);
             headers
         })
-        .build()?;
+        .build()?)
+}
 
-    // Get champion data
-    let champions = get_champions(&client).await?;
-    
-    // Connect websocket
-    let ws_url = format!("wss://127.0.0.1:{}", connection.port);
-    let (mut ws_stream, _) = connect_async(
-        Url::parse(&ws_url)?.to_string()
-    ).await?;
+/// Polls `LcuConnection::find()` until the League client is found, backing
+/// off from `RECONNECT_POLL_MIN` up to `RECONNECT_POLL_MAX` between misses.
+async fn find_connection_with_backoff() -> LcuConnection {
+    let mut delay = RECONNECT_POLL_MIN;
+    loop {
+        if let Some(connection) = LcuConnection::find() {
+            return connection;
+        }
+        sleep(delay).await;
+        delay = (delay * 2).min(RECONNECT_POLL_MAX);
+    }
+}
 
-    // Subscribe to events
-    ws_stream.send(Message::Text(json!([5, "OnJsonApiEvent"]).to_string())).await?;
+/// Where the resolved champion map is cached on disk, keyed by patch version
+/// so we only re-download `champion.json` when the patch actually changes.
+const CHAMPION_CACHE_PATH: &str = "champion_cache.json";
+
+/// Loads champion data for `pinned_version` (or whatever DataDragon reports
+/// as current), preferring the on-disk cache when its version still matches
+/// and falling back to a stale cache if the network is unreachable.
+async fn get_champions(client: &Client, pinned_version: Option<&str>) -> Result<(HashMap<String, u32>, ChampionResolver)> {
+    let cache = load_champion_cache();
+
+    let version = match pinned_version {
+        Some(v) => v.to_string(),
+        None => match fetch_latest_version(client).await {
+            Ok(v) => v,
+            Err(e) => {
+                return match cache {
+                    Some(cache) => {
+                        eprintln!("Could not reach DataDragon ({}), using stale cached patch {}", e, cache.version);
+                        Ok(resolver_for(cache))
+                    }
+                    None => Err(e),
+                };
+            }
+        }
+    };
 
-    let game_state = Arc::new(Mutex::new(GameState::default()));
+    if let Some(cache) = &cache {
+        if cache.version == version {
+            println!("Champion data for patch {} is already cached", version);
+            return Ok(resolver_for(cache.clone()));
+        }
+    }
 
-    // Main event loop
-    while let Some(msg) = ws_stream.next().await {
-        let msg = msg?;
-        if let Message::Text(text) = msg {
-            handle_message(&client, &text, &champions, picks, bans, Arc::clone(&game_state), connection.port).await?;
+    match fetch_champion_data(client, &version).await {
+        Ok((champions, display_names)) => {
+            let cache = ChampionCache { version, champions, display_names };
+            save_champion_cache(&cache);
+            Ok(resolver_for(cache))
+        }
+        Err(e) => match cache {
+            Some(cache) => {
+                eprintln!("Failed to download champion data ({}), falling back to stale cached patch {}", e, cache.version);
+                Ok(resolver_for(cache))
+            }
+            None => Err(e),
         }
     }
+}
 
-    Ok(())
+#[derive(Clone)]
+struct ChampionCache {
+    version: String,
+    champions: HashMap<String, u32>,
+    display_names: HashMap<String, String>,
+}
+
+fn resolver_for(cache: ChampionCache) -> (HashMap<String, u32>, ChampionResolver) {
+    let resolver = ChampionResolver::new(cache.champions.keys().cloned(), &cache.display_names);
+    (cache.champions, resolver)
 }
 
-async fn get_champions(client: &Client) -> Result<HashMap<String, u32>> {
-    let version = client.get("https://ddragon.leagueoflegends.com/api/versions.json")
+fn load_champion_cache() -> Option<ChampionCache> {
+    let raw = std::fs::read_to_string(CHAMPION_CACHE_PATH).ok()?;
+    let cache: Value = serde_json::from_str(&raw).ok()?;
+
+    let version = cache["version"].as_str()?.to_string();
+    let champions: HashMap<String, u32> = serde_json::from_value(cache["champions"].clone()).ok()?;
+    let display_names: HashMap<String, String> = serde_json::from_value(cache["display_names"].clone()).ok()?;
+
+    Some(ChampionCache { version, champions, display_names })
+}
+
+fn save_champion_cache(cache: &ChampionCache) {
+    let value = json!({
+        "version": cache.version,
+        "champions": cache.champions,
+        "display_names": cache.display_names,
+    });
+
+    if let Err(e) = std::fs::write(CHAMPION_CACHE_PATH, value.to_string()) {
+        eprintln!("Warning: failed to write champion cache to {}: {}", CHAMPION_CACHE_PATH, e);
+    }
+}
+
+async fn fetch_latest_version(client: &Client) -> Result<String> {
+    let versions = client.get("https://ddragon.leagueoflegends.com/api/versions.json")
         .send()
         .await?
         .json::<Vec<String>>()
         .await?;
-        
-    let version = &version[0];
 
+    versions.into_iter().next().ok_or_else(|| anyhow!("DataDragon returned no versions"))
+}
+
+async fn fetch_champion_data(client: &Client, version: &str) -> Result<(HashMap<String, u32>, HashMap<String, String>)> {
     let champions = client.get(&format!(
         "https://ddragon.leagueoflegends.com/cdn/{}/data/en_US/champion.json",
         version
@@ -169,6 +366,7 @@ async fn get_champions(client: &Client) -> Result<HashMap<String, u32>> {
     .await?;
 
     let mut map = HashMap::new();
+    let mut display_names = HashMap::new();
     if let Some(data) = champions["data"].as_object() {
         for (name, champion) in data {
             if let Some(key) = champion["key"].as_str() {
@@ -176,184 +374,277 @@ async fn get_champions(client: &Client) -> Result<HashMap<String, u32>> {
                     map.insert(name.clone(), key);
                 }
             }
+            if let Some(display_name) = champion["name"].as_str() {
+                display_names.insert(name.clone(), display_name.to_string());
+            }
         }
     }
 
-    println!("len(champions_map)={}, champions_map={:?}", map.len(), map);
+    println!("Fetched {} champions for patch {}", map.len(), version);
+
+    Ok((map, display_names))
+}
+
+/// Resolves every champion name in a `[picks]`/`[bans]`-style table to its
+/// canonical DataDragon key, warning (but not failing) on anything that
+/// can't be resolved so a typo doesn't silently do nothing mid-pick.
+fn resolve_champion_names(lists: &Map<String, Value>, resolver: &ChampionResolver, label: &str) -> Map<String, Value> {
+    let mut resolved = Map::new();
+    for (role, entries) in lists {
+        let Some(entries) = entries.as_array() else {
+            resolved.insert(role.clone(), entries.clone());
+            continue;
+        };
+
+        let resolved_entries: Vec<Value> = entries.iter().map(|entry| {
+            let Some(name) = entry.as_str() else {
+                return entry.clone();
+            };
+            match resolver.resolve(name) {
+                Some(canonical) => Value::String(canonical.to_string()),
+                None => {
+                    eprintln!("Warning: could not resolve champion \"{}\" in [{}.{}], check for typos", name, label, role);
+                    entry.clone()
+                }
+            }
+        }).collect();
 
-    Ok(map)
+        resolved.insert(role.clone(), Value::Array(resolved_entries));
+    }
+    resolved
 }
 
-async fn handle_message(
-    client: &Client,
+/// Max attempts for a single champ-select action PATCH before giving up on
+/// that champion and moving to the next one in the list.
+const MAX_ACTION_ATTEMPTS: u32 = 5;
+/// Upper bound on the backoff between retries when the LCU doesn't send a
+/// `Retry-After` header.
+const MAX_ACTION_BACKOFF: Duration = Duration::from_secs(5);
+
+enum ActionOutcome {
+    /// The LCU accepted the action.
+    Completed,
+    /// A genuine 4xx (e.g. champion unavailable) — try the next champion.
+    Rejected,
+    /// Attempted `MAX_ACTION_ATTEMPTS` times without success.
+    GaveUp,
+}
+
+/// PATCHes a champ-select action, retrying on 429/5xx (honoring
+/// `Retry-After` when present, otherwise exponential backoff) for up to
+/// `MAX_ACTION_ATTEMPTS` attempts in total. Only a genuine 4xx is reported as
+/// `Rejected`; a 429/5xx response is never mistaken for "champion unavailable".
+async fn submit_action<C: LcuClient>(
+    client: &C,
+    limiter: &RateLimiter,
+    action_id: i64,
+    champion_id: u32,
+    completed: bool,
+) -> Result<ActionOutcome> {
+    let mut backoff = Duration::from_millis(500);
+
+    for attempt in 1..=MAX_ACTION_ATTEMPTS {
+        limiter.acquire().await;
+        let response = client.patch(&format!("/lol-champ-select/v1/session/actions/{}", action_id), json!({
+            "championId": champion_id,
+            "completed": completed
+        })).await?;
+
+        let status = response.status;
+        if status.is_success() {
+            return Ok(ActionOutcome::Completed);
+        }
+
+        if status.as_u16() == 429 || status.is_server_error() {
+            let retry_after = response.headers
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(Duration::from_secs);
+
+            // Cap an LCU-supplied `Retry-After` the same as the synthetic
+            // backoff — a large/malformed header must not stall this task
+            // for longer than `MAX_ACTION_BACKOFF`.
+            let wait = retry_after.unwrap_or(backoff).min(MAX_ACTION_BACKOFF);
+            println!("Action PATCH returned {} (attempt {}/{}), retrying in {:?}", status, attempt, MAX_ACTION_ATTEMPTS, wait);
+            sleep(wait).await;
+            backoff = (backoff * 2).min(MAX_ACTION_BACKOFF);
+            continue;
+        }
+
+        // A genuine 4xx, e.g. "champion unavailable" — no point retrying.
+        return Ok(ActionOutcome::Rejected);
+    }
+
+    Ok(ActionOutcome::GaveUp)
+}
+
+async fn handle_message<C: LcuClient>(
+    client: &C,
     msg: &str,
     champions: &HashMap<String, u32>,
-    picks: &[Value],
-    bans: &[Value],
+    picks: &Map<String, Value>,
+    bans: &Map<String, Value>,
     game_state: Arc<Mutex<GameState>>,
-    port: u16
+    limiter: Arc<RateLimiter>,
 ) -> Result<()> {
     let data: Value = serde_json::from_str(msg)?;
-    
+
     if let Some(data) = data.get(2) {
         match data["uri"].as_str() {
             Some("/lol-matchmaking/v1/ready-check") => {
                 if data["data"]["state"] == "InProgress" && data["data"]["playerResponse"] == "None" {
-                    client.post(format!("https://127.0.0.1:{}/lol-matchmaking/v1/ready-check/accept", port))
-                        .send()
-                        .await?;
+                    limiter.acquire().await;
+                    client.post("/lol-matchmaking/v1/ready-check/accept").await?;
                 }
             }
             Some("/lol-champ-select/v1/session") => {
-                let mut state = game_state.lock().unwrap();
-                state.have_i_prepicked = false;
+                let session: ChampSelectSession = serde_json::from_value(data["data"].clone())?;
 
-                let lobby_phase = data["data"]["timer"]["phase"].as_str().unwrap_or("");
-                let local_player_cell_id = data["data"]["localPlayerCellId"].as_i64().unwrap_or(-1);
+                let lobby_phase = session.timer.phase.clone();
+                let local_player_cell_id = session.local_player_cell_id;
 
-                // Track assigned position
-                if let Some(my_team) = data["data"]["myTeam"].as_array() {
-                    for teammate in my_team {
-                        if teammate["cellId"] == local_player_cell_id {
-                            let assigned_position = teammate["assignedPosition"].as_str().unwrap_or("");
-                            state.am_i_assigned = true;
-                            println!("Assigned position: {}", assigned_position);
-                        }
-                    }
-                }
+                let all_actions: Vec<Action> = session.actions.iter().flatten().cloned().collect();
 
                 // Track banned champions
-                let mut banned_champions = Vec::new();
-                if let Some(actions) = data["data"]["actions"].as_array() {
-                    for action_list in actions {
-                        if let Some(actions) = action_list.as_array() {
-                            for action in actions {
-                                if action["type"] == "ban" && action["completed"] == true {
-                                    if let Some(champion_id) = action["championId"].as_u64() {
-                                        banned_champions.push(champion_id as u32);
-                                    }
+                let banned_champions: Vec<u32> = all_actions.iter()
+                    .filter(|action| action.action_type == ActionType::Ban && action.completed)
+                    .map(|action| action.champion_id)
+                    .collect();
+
+                // Update the plain session-tracking fields and snapshot what
+                // the rest of this function needs, all without ever crossing
+                // an `.await` while the lock is held — the reconnect
+                // supervisor resets this same mutex on disconnect, and a
+                // multi-second retry/backoff inside `submit_action` must not
+                // stall that reset.
+                let (assigned_role, action_id, phase, am_i_banning, am_i_picking) = {
+                    let mut state = game_state.lock().unwrap();
+
+                    // Track assigned position
+                    for teammate in &session.my_team {
+                        if teammate.cell_id == local_player_cell_id {
+                            state.am_i_assigned = true;
+                            state.assigned_role = teammate.assigned_position.clone();
+                            println!("Assigned position: {}", teammate.assigned_position);
+
+                            if !state.warned_role_unconfigured {
+                                state.warned_role_unconfigured = true;
+                                if !state.assigned_role.is_empty()
+                                    && !role_configured(picks, &state.assigned_role)
+                                    && !role_configured(bans, &state.assigned_role)
+                                {
+                                    eprintln!(
+                                        "Warning: assigned role \"{}\" has no [picks.*]/[bans.*] entry, falling back to default",
+                                        state.assigned_role
+                                    );
                                 }
                             }
                         }
                     }
-                }
 
-                // Find current action
-                if let Some(actions) = data["data"]["actions"].as_array() {
-                    for action_list in actions {
-                        if let Some(actions) = action_list.as_array() {
-                            for action in actions {
-                                if action["actorCellId"] == local_player_cell_id && action["isInProgress"] == true {
-                                    state.phase = action["type"].as_str().unwrap_or("").to_string();
-                                    state.action_id = action["id"].as_i64();
-                                    
-                                    if state.phase == "ban" {
-                                        state.am_i_banning = action["isInProgress"].as_bool().unwrap_or(false);
-                                    }
-                                    if state.phase == "pick" {
-                                        state.am_i_picking = action["isInProgress"].as_bool().unwrap_or(false);
-                                    }
-                                }
+                    // Find current action
+                    let mut am_i_banning = false;
+                    let mut am_i_picking = false;
+                    for action in &all_actions {
+                        if action.actor_cell_id == local_player_cell_id && action.is_in_progress {
+                            state.action_id = Some(action.id);
+                            match action.action_type {
+                                ActionType::Ban => am_i_banning = true,
+                                ActionType::Pick => am_i_picking = true,
+                                ActionType::Unknown(_) => {}
                             }
+                            state.phase = Some(action.action_type.clone());
                         }
                     }
-                }
+
+                    (state.assigned_role.clone(), state.action_id, state.phase.clone(), am_i_banning, am_i_picking)
+                };
 
                 // Handle banning phase
-                if state.phase == "ban" && lobby_phase == "BAN_PICK" && state.am_i_banning {
-                    while state.am_i_banning && state.ban_number < bans.len() {
-                        if let Some(ban) = bans[state.ban_number].as_str() {
-                            if let Some(champion_id) = champions.get(ban) {
-                                let result = client.patch(format!("https://127.0.0.1:{}/lol-champ-select/v1/session/actions/{}", 
-                                    port, state.action_id.unwrap()))
-                                    .json(&json!({
-                                        "championId": champion_id,
-                                        "completed": true
-                                    }))
-                                    .send()
-                                    .await;
-
-                                match result {
-                                    Ok(_) => {
-                                        println!("Successfully banned {}", ban);
-                                        break;
-                                    }
-                                    Err(e) => {
-                                        println!("Failed to ban {}: {}", ban, e);
-                                        state.ban_number += 1;
-                                        if state.ban_number >= bans.len() {
-                                            state.pick_number = 0;
-                                        }
-                                    }
-                                }
+                if phase == Some(ActionType::Ban) && lobby_phase == ChampSelectPhase::BanPick && am_i_banning {
+                    let bans = role_list(bans, &assigned_role);
+                    let mut ban_number = 0;
+                    while ban_number < bans.len() {
+                        let Some(ban) = bans[ban_number].as_str() else {
+                            ban_number += 1;
+                            continue;
+                        };
+                        let Some(&champion_id) = champions.get(ban) else {
+                            println!("{} did not resolve to a champion id, trying next", ban);
+                            ban_number += 1;
+                            continue;
+                        };
+
+                        let outcome = submit_action(client, &limiter, action_id.unwrap(), champion_id, true).await?;
+                        match outcome {
+                            ActionOutcome::Completed => {
+                                println!("Successfully banned {}", ban);
+                                break;
+                            }
+                            ActionOutcome::Rejected => {
+                                println!("{} can't be banned, trying next", ban);
+                                ban_number += 1;
+                            }
+                            ActionOutcome::GaveUp => {
+                                println!("Giving up banning {} after {} attempts, trying next", ban, MAX_ACTION_ATTEMPTS);
+                                ban_number += 1;
                             }
                         }
                     }
-                    state.ban_number = 0;
-                    state.am_i_banning = false;
                 }
 
                 // Handle picking phase
-                if state.phase == "pick" && lobby_phase == "BAN_PICK" && state.am_i_picking {
-                    while state.am_i_picking && state.pick_number < picks.len() {
-                        if let Some(pick) = picks[state.pick_number].as_str() {
-                            if let Some(champion_id) = champions.get(pick) {
-                                // Check if champion is banned
-                                if banned_champions.contains(champion_id) {
-                                    println!("{} is banned, trying next pick", pick);
-                                    state.pick_number += 1;
-                                    continue;
-                                }
+                if phase == Some(ActionType::Pick) && lobby_phase == ChampSelectPhase::BanPick && am_i_picking {
+                    let role_picks = role_list(picks, &assigned_role);
+                    let mut pick_number = 0;
+                    while pick_number < role_picks.len() {
+                        let Some(pick) = role_picks[pick_number].as_str() else {
+                            pick_number += 1;
+                            continue;
+                        };
+                        let Some(&champion_id) = champions.get(pick) else {
+                            println!("{} did not resolve to a champion id, trying next", pick);
+                            pick_number += 1;
+                            continue;
+                        };
+
+                        // Check if champion is banned
+                        if banned_champions.contains(&champion_id) {
+                            println!("{} is banned, trying next pick", pick);
+                            pick_number += 1;
+                            continue;
+                        }
 
-                                let result = client.patch(format!("https://127.0.0.1:{}/lol-champ-select/v1/session/actions/{}", 
-                                    port, state.action_id.unwrap()))
-                                    .json(&json!({
-                                        "championId": champion_id,
-                                        "completed": true
-                                    }))
-                                    .send()
-                                    .await;
-
-                                match result {
-                                    Ok(_) => {
-                                        println!("Successfully picked {}", pick);
-                                        break;
-                                    }
-                                    Err(e) => {
-                                        println!("Failed to pick {}: {}", pick, e);
-                                        state.pick_number += 1;
-                                        if state.pick_number >= picks.len() {
-                                            state.pick_number = 0;
-                                        }
-                                    }
-                                }
+                        let outcome = submit_action(client, &limiter, action_id.unwrap(), champion_id, true).await?;
+                        match outcome {
+                            ActionOutcome::Completed => {
+                                println!("Successfully picked {}", pick);
+                                break;
+                            }
+                            ActionOutcome::Rejected => {
+                                println!("{} can't be picked, trying next", pick);
+                                pick_number += 1;
+                            }
+                            ActionOutcome::GaveUp => {
+                                println!("Giving up picking {} after {} attempts, trying next", pick, MAX_ACTION_ATTEMPTS);
+                                pick_number += 1;
                             }
                         }
                     }
-                    state.pick_number = 0;
-                    state.am_i_picking = false;
                 }
 
                 // Handle planning phase
-                if lobby_phase == "PLANNING" && !state.have_i_prepicked {
-                    if let Some(pick) = picks[0].as_str() {
-                        if let Some(champion_id) = champions.get(pick) {
-                            let result = client.patch(format!("https://127.0.0.1:{}/lol-champ-select/v1/session/actions/{}", 
-                                port, state.action_id.unwrap()))
-                                .json(&json!({
-                                    "championId": champion_id,
-                                    "completed": false
-                                }))
-                                .send()
-                                .await;
-
-                            match result {
-                                Ok(_) => {
-                                    println!("Pre-picked {}", pick);
-                                    state.have_i_prepicked = true;
-                                }
-                                Err(e) => {
-                                    println!("Failed to pre-pick {}: {}", pick, e);
+                if lobby_phase == ChampSelectPhase::Planning {
+                    let role_picks = role_list(picks, &assigned_role);
+                    if let Some(pick) = role_picks.first().and_then(|v| v.as_str()) {
+                        if let Some(&champion_id) = champions.get(pick) {
+                            let outcome = submit_action(client, &limiter, action_id.unwrap(), champion_id, false).await?;
+                            match outcome {
+                                ActionOutcome::Completed => println!("Pre-picked {}", pick),
+                                ActionOutcome::Rejected => println!("Could not pre-pick {}", pick),
+                                ActionOutcome::GaveUp => {
+                                    println!("Giving up pre-picking {} after {} attempts", pick, MAX_ACTION_ATTEMPTS);
                                 }
                             }
                         }
@@ -361,18 +652,22 @@ async fn handle_message(
                 }
 
                 // Handle game start
-                if lobby_phase == "FINALIZATION" {
-                    let game_phase = client.get(format!("https://127.0.0.1:{}/lol-gameflow/v1/gameflow-phase", port))
-                        .send()
+                if lobby_phase == ChampSelectPhase::Finalization {
+                    limiter.acquire().await;
+                    let game_phase: GameflowPhase = client.get("/lol-gameflow/v1/gameflow-phase")
                         .await?
-                        .text()
-                        .await?;
+                        .body
+                        .parse()
+                        .unwrap();
 
-                    if game_phase == "InGame" && !state.in_game {
+                    let mut state = game_state.lock().unwrap();
+                    if game_phase == GameflowPhase::InGame && !state.in_game {
                         println!("Game started! Exiting champion select bot...");
                         state.in_game = true;
+                        drop(state);
                         exit(69);
                     }
+                    drop(state);
                     sleep(Duration::from_secs(2)).await;
                 }
             }
@@ -382,3 +677,163 @@ async fn handle_message(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lcu_client::{LcuResponse, MockLcuClient};
+    use reqwest::StatusCode;
+
+    fn role_table(entries: &[&str]) -> Map<String, Value> {
+        let mut table = Map::new();
+        table.insert("default".to_string(), json!(entries));
+        table
+    }
+
+    #[tokio::test]
+    async fn skips_banned_champion_and_picks_next() {
+        let client = MockLcuClient::new();
+        let champions = HashMap::from([("Ahri".to_string(), 103), ("Zed".to_string(), 238)]);
+        let picks = role_table(&["Ahri", "Zed"]);
+        let bans = role_table(&[]);
+
+        let msg = json!([8, "OnJsonApiEvent", {
+            "uri": "/lol-champ-select/v1/session",
+            "data": {
+                "localPlayerCellId": 1,
+                "timer": {"phase": "BAN_PICK"},
+                "myTeam": [{"cellId": 1, "assignedPosition": ""}],
+                "actions": [[
+                    {"id": 1, "actorCellId": 5, "type": "ban", "championId": 103, "completed": true, "isInProgress": false},
+                    {"id": 2, "actorCellId": 1, "type": "pick", "championId": 0, "completed": false, "isInProgress": true}
+                ]]
+            }
+        }]).to_string();
+
+        handle_message(&client, &msg, &champions, &picks, &bans, Arc::new(Mutex::new(GameState::default())), Arc::new(RateLimiter::default_lcu_limits())).await.unwrap();
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method, "PATCH");
+        assert_eq!(calls[0].path, "/lol-champ-select/v1/session/actions/2");
+        assert_eq!(calls[0].body.as_ref().unwrap()["championId"], 238);
+        assert_eq!(calls[0].body.as_ref().unwrap()["completed"], true);
+    }
+
+    #[tokio::test]
+    async fn prepicks_during_planning() {
+        let client = MockLcuClient::new();
+        let champions = HashMap::from([("Ahri".to_string(), 103)]);
+        let picks = role_table(&["Ahri"]);
+        let bans = role_table(&[]);
+
+        let msg = json!([8, "OnJsonApiEvent", {
+            "uri": "/lol-champ-select/v1/session",
+            "data": {
+                "localPlayerCellId": 1,
+                "timer": {"phase": "PLANNING"},
+                "myTeam": [{"cellId": 1, "assignedPosition": ""}],
+                "actions": [[
+                    {"id": 7, "actorCellId": 1, "type": "pick", "championId": 0, "completed": false, "isInProgress": true}
+                ]]
+            }
+        }]).to_string();
+
+        handle_message(&client, &msg, &champions, &picks, &bans, Arc::new(Mutex::new(GameState::default())), Arc::new(RateLimiter::default_lcu_limits())).await.unwrap();
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].path, "/lol-champ-select/v1/session/actions/7");
+        assert_eq!(calls[0].body.as_ref().unwrap()["championId"], 103);
+        assert_eq!(calls[0].body.as_ref().unwrap()["completed"], false);
+    }
+
+    #[tokio::test]
+    async fn submit_action_retries_429_then_completes() {
+        let client = MockLcuClient::new();
+        client.push_response(LcuResponse::with_retry_after(StatusCode::TOO_MANY_REQUESTS, 0));
+        client.push_response(LcuResponse::with_status(StatusCode::OK));
+        let limiter = RateLimiter::default_lcu_limits();
+
+        let outcome = submit_action(&client, &limiter, 1, 103, true).await.unwrap();
+
+        assert!(matches!(outcome, ActionOutcome::Completed));
+        assert_eq!(client.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn submit_action_rejects_on_genuine_4xx_without_retrying() {
+        let client = MockLcuClient::new();
+        client.push_response(LcuResponse::with_status(StatusCode::BAD_REQUEST));
+        let limiter = RateLimiter::default_lcu_limits();
+
+        let outcome = submit_action(&client, &limiter, 1, 103, true).await.unwrap();
+
+        assert!(matches!(outcome, ActionOutcome::Rejected));
+        assert_eq!(client.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_action_gives_up_after_max_attempts_of_5xx() {
+        let client = MockLcuClient::new();
+        for _ in 0..MAX_ACTION_ATTEMPTS {
+            client.push_response(LcuResponse::with_retry_after(StatusCode::SERVICE_UNAVAILABLE, 0));
+        }
+        let limiter = RateLimiter::default_lcu_limits();
+
+        let outcome = submit_action(&client, &limiter, 1, 103, true).await.unwrap();
+
+        assert!(matches!(outcome, ActionOutcome::GaveUp));
+        assert_eq!(client.calls().len(), MAX_ACTION_ATTEMPTS as usize);
+    }
+
+    #[tokio::test]
+    async fn picks_role_specific_list_case_insensitively() {
+        let client = MockLcuClient::new();
+        let champions = HashMap::from([("Ahri".to_string(), 103), ("Garen".to_string(), 86)]);
+        let mut picks = Map::new();
+        picks.insert("TOP".to_string(), json!(["Garen"]));
+        picks.insert("default".to_string(), json!(["Ahri"]));
+        let bans = role_table(&[]);
+
+        // The LCU reports `assignedPosition` lowercase, not matching the
+        // config's conventional uppercase `[picks.TOP]` spelling verbatim.
+        let msg = json!([8, "OnJsonApiEvent", {
+            "uri": "/lol-champ-select/v1/session",
+            "data": {
+                "localPlayerCellId": 1,
+                "timer": {"phase": "BAN_PICK"},
+                "myTeam": [{"cellId": 1, "assignedPosition": "top"}],
+                "actions": [[
+                    {"id": 2, "actorCellId": 1, "type": "pick", "championId": 0, "completed": false, "isInProgress": true}
+                ]]
+            }
+        }]).to_string();
+
+        handle_message(&client, &msg, &champions, &picks, &bans, Arc::new(Mutex::new(GameState::default())), Arc::new(RateLimiter::default_lcu_limits())).await.unwrap();
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].body.as_ref().unwrap()["championId"], 86);
+    }
+
+    #[tokio::test]
+    async fn accepts_ready_check() {
+        let client = MockLcuClient::new();
+        let champions = HashMap::new();
+        let picks = role_table(&[]);
+        let bans = role_table(&[]);
+
+        let msg = json!([8, "OnJsonApiEvent", {
+            "uri": "/lol-matchmaking/v1/ready-check",
+            "data": {"state": "InProgress", "playerResponse": "None"}
+        }]).to_string();
+
+        handle_message(&client, &msg, &champions, &picks, &bans, Arc::new(Mutex::new(GameState::default())), Arc::new(RateLimiter::default_lcu_limits())).await.unwrap();
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method, "POST");
+        assert_eq!(calls[0].path, "/lol-matchmaking/v1/ready-check/accept");
+    }
+}